@@ -0,0 +1,84 @@
+//! `#[derive(Nondet)]` — synthesize a [`Nondet`] impl field by field.
+//!
+//! For a struct, every field is drawn with `Nondet::nondet`. For an enum, a
+//! discriminant is first drawn with `CVT_nondet_u8 % variant_count`, pinned with
+//! `CVT_assume` so the Prover only explores legal discriminants, and then the
+//! chosen variant's fields are filled.
+//!
+//! [`Nondet`]: cvt::nondet::Nondet
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Index};
+
+#[proc_macro_derive(Nondet)]
+pub fn derive_nondet(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let ctor = construct_fields(quote!(Self), &data.fields);
+            quote!(#ctor)
+        }
+        Data::Enum(data) => {
+            let variant_count = data.variants.len() as u8;
+            let arms = data.variants.iter().enumerate().map(|(i, variant)| {
+                let i = i as u8;
+                let vname = &variant.ident;
+                let ctor = construct_fields(quote!(Self::#vname), &variant.fields);
+                quote! { #i => #ctor, }
+            });
+            quote! {
+                // Draw a discriminant and pin it to the legal range so the
+                // Prover only explores variants that actually exist.
+                let __disc = cvt::prelude::nondet_u8() % #variant_count;
+                cvt::prelude::assume(__disc < #variant_count);
+                match __disc {
+                    #(#arms)*
+                    _ => unsafe { core::hint::unreachable_unchecked() },
+                }
+            }
+        }
+        Data::Union(_) => {
+            return syn::Error::new_spanned(
+                name,
+                "`#[derive(Nondet)]` is not supported for unions",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+
+    let expanded = quote! {
+        impl #impl_generics cvt::nondet::Nondet for #name #ty_generics #where_clause {
+            fn nondet() -> Self {
+                #body
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+/// Build a struct/variant constructor drawing each field with `Nondet::nondet`.
+fn construct_fields(path: proc_macro2::TokenStream, fields: &Fields) -> proc_macro2::TokenStream {
+    match fields {
+        Fields::Named(named) => {
+            let inits = named.named.iter().map(|f| {
+                let fname = f.ident.as_ref().unwrap();
+                quote! { #fname: cvt::nondet::Nondet::nondet() }
+            });
+            quote! { #path { #(#inits),* } }
+        }
+        Fields::Unnamed(unnamed) => {
+            let inits = (0..unnamed.unnamed.len()).map(|i| {
+                let _ = Index::from(i);
+                quote! { cvt::nondet::Nondet::nondet() }
+            });
+            quote! { #path(#(#inits),*) }
+        }
+        Fields::Unit => quote! { #path },
+    }
+}
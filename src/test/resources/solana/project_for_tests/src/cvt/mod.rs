@@ -0,0 +1,16 @@
+//! Safe, ergonomic surface over the raw [`crate::intrinsics`].
+//!
+//! Harness authors should reach for [`prelude`] rather than calling the
+//! `extern` intrinsics directly: the wrappers confine the single `unsafe` call
+//! to one place, so rules can be written `#![deny(unsafe_op_in_unsafe_fn)]`-clean
+//! without sprinkling `unsafe` blocks across every harness. The raw intrinsics
+//! remain available in [`crate::intrinsics`] for advanced use.
+
+pub mod nondet;
+pub mod prelude;
+
+#[doc(inline)]
+pub use nondet::Nondet;
+
+/// `#[derive(Nondet)]` for domain structs and enums.
+pub use cvt_derive::Nondet;
@@ -0,0 +1,127 @@
+//! The [`Nondet`] trait: types that can be drawn nondeterministically from the
+//! Prover.
+//!
+//! Primitive impls bottom out at the corresponding `CVT_nondet_*` intrinsic
+//! (via [`crate::cvt::prelude`]); compound impls assemble a value field by
+//! field. Selectors that must range over a bounded set (booleans, discriminants)
+//! are pinned with [`prelude::assume`] so the Prover only explores legal values.
+//!
+//! Domain structs and enums should obtain an impl with `#[derive(Nondet)]`
+//! rather than hand-assembling scalars.
+
+use crate::cvt::prelude;
+
+/// A type that can be instantiated with a fresh nondeterministic value.
+pub trait Nondet {
+    /// Draw a nondeterministic value of `Self` from the Prover.
+    fn nondet() -> Self;
+}
+
+/// Implement [`Nondet`] for a primitive by delegating to its width wrapper.
+macro_rules! impl_nondet_primitive {
+    ($($ty:ty => $wrapper:path),+ $(,)?) => {$(
+        impl Nondet for $ty {
+            #[inline]
+            fn nondet() -> Self {
+                $wrapper()
+            }
+        }
+    )+};
+}
+
+impl_nondet_primitive! {
+    u8 => prelude::nondet_u8,
+    u16 => prelude::nondet_u16,
+    u32 => prelude::nondet_u32,
+    u64 => prelude::nondet_u64,
+    u128 => prelude::nondet_u128,
+    i8 => prelude::nondet_i8,
+    i16 => prelude::nondet_i16,
+    i32 => prelude::nondet_i32,
+    i64 => prelude::nondet_i64,
+    i128 => prelude::nondet_i128,
+    f32 => prelude::nondet_f32,
+    f64 => prelude::nondet_f64,
+}
+
+impl Nondet for usize {
+    #[inline]
+    fn nondet() -> Self {
+        prelude::nondet_u64() as usize
+    }
+}
+
+impl Nondet for isize {
+    #[inline]
+    fn nondet() -> Self {
+        prelude::nondet_i64() as isize
+    }
+}
+
+impl Nondet for bool {
+    #[inline]
+    fn nondet() -> Self {
+        let b = prelude::nondet_u8();
+        // Constrain the selector so the Prover only sees the two legal values.
+        prelude::assume(b <= 1);
+        b == 1
+    }
+}
+
+impl Nondet for char {
+    #[inline]
+    fn nondet() -> Self {
+        let c = prelude::nondet_u32();
+        match char::from_u32(c) {
+            Some(ch) => ch,
+            None => {
+                // Prune paths whose draw is not a valid Unicode scalar value.
+                prelude::assume(false);
+                '\0'
+            }
+        }
+    }
+}
+
+impl<T: Nondet, const N: usize> Nondet for [T; N] {
+    #[inline]
+    fn nondet() -> Self {
+        core::array::from_fn(|_| T::nondet())
+    }
+}
+
+impl<T: Nondet> Nondet for Option<T> {
+    #[inline]
+    fn nondet() -> Self {
+        if bool::nondet() {
+            Some(T::nondet())
+        } else {
+            None
+        }
+    }
+}
+
+/// Implement [`Nondet`] for a tuple by drawing each element in turn.
+macro_rules! impl_nondet_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: Nondet),+> Nondet for ($($name,)+) {
+            #[inline]
+            fn nondet() -> Self {
+                ($($name::nondet(),)+)
+            }
+        }
+    };
+}
+
+impl_nondet_tuple!(A);
+impl_nondet_tuple!(A, B);
+impl_nondet_tuple!(A, B, C);
+impl_nondet_tuple!(A, B, C, D);
+impl_nondet_tuple!(A, B, C, D, E);
+impl_nondet_tuple!(A, B, C, D, E, F);
+impl_nondet_tuple!(A, B, C, D, E, F, G);
+impl_nondet_tuple!(A, B, C, D, E, F, G, H);
+impl_nondet_tuple!(A, B, C, D, E, F, G, H, I);
+impl_nondet_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_nondet_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_nondet_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
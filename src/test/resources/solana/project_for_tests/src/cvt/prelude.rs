@@ -0,0 +1,151 @@
+//! Safe wrappers over the raw `CVT_*` intrinsics.
+//!
+//! Each wrapper performs exactly one `unsafe` call into [`crate::intrinsics`]
+//! and exposes an ordinary safe `fn`, so the common verification path never
+//! needs an `unsafe` block.
+
+use crate::intrinsics;
+
+/// Generate a safe `nondet_*` wrapper for each primitive-width intrinsic.
+macro_rules! nondet_wrappers {
+    ($($wrapper:ident => $intrinsic:ident -> $ty:ty),+ $(,)?) => {$(
+        #[doc = concat!("Draw a fresh nondeterministic `", stringify!($ty), "` from the Prover.")]
+        #[inline]
+        pub fn $wrapper() -> $ty {
+            // SAFETY: the intrinsic has no preconditions; it only introduces a
+            // fresh symbolic value of the given width.
+            unsafe { intrinsics::$intrinsic() }
+        }
+    )+};
+}
+
+nondet_wrappers! {
+    nondet_u8 => CVT_nondet_u8 -> u8,
+    nondet_u16 => CVT_nondet_u16 -> u16,
+    nondet_u32 => CVT_nondet_u32 -> u32,
+    nondet_u64 => CVT_nondet_u64 -> u64,
+    nondet_u128 => CVT_nondet_u128 -> u128,
+    nondet_i8 => CVT_nondet_i8 -> i8,
+    nondet_i16 => CVT_nondet_i16 -> i16,
+    nondet_i32 => CVT_nondet_i32 -> i32,
+    nondet_i64 => CVT_nondet_i64 -> i64,
+    nondet_i128 => CVT_nondet_i128 -> i128,
+    nondet_f32 => CVT_nondet_f32 -> f32,
+    nondet_f64 => CVT_nondet_f64 -> f64,
+}
+
+/// Draw `N` nondeterministic bytes, one `CVT_nondet_u8` per element.
+#[inline]
+pub fn nondet_bytes<const N: usize>() -> [u8; N] {
+    core::array::from_fn(|_| nondet_u8())
+}
+
+/// Draw a nondeterministic value of an arbitrary POD type `T` by filling
+/// `size_of::<T>()` nondet bytes and reinterpreting them as a `T`.
+///
+/// This reaches types the `#[derive(Nondet)]` macro cannot — packed layouts,
+/// `repr(C)` blobs — at the cost of byte-level reconstruction.
+///
+/// # Hazard
+///
+/// An all-nondet bit pattern need not be a valid inhabitant of `T` (the same
+/// caveat as `assert_uninit_valid`): a nondet byte interpreted as a `bool`,
+/// `char`, enum discriminant, or non-null pointer may be an illegal
+/// representation, which is undefined behaviour. Callers should follow a
+/// `nondet_pod` with [`assume`] constraints that exclude the illegal
+/// representations for their `T`.
+#[inline]
+pub fn nondet_pod<T: Copy>() -> T {
+    let mut value = core::mem::MaybeUninit::<T>::uninit();
+    let ptr = value.as_mut_ptr() as *mut u8;
+    // SAFETY: `ptr` points at `size_of::<T>()` writable bytes; we fill every one
+    // with a nondet byte before reading the value back out. Validity of the
+    // resulting bit pattern is the caller's responsibility (see the hazard note).
+    unsafe {
+        for i in 0..core::mem::size_of::<T>() {
+            ptr.add(i).write(nondet_u8());
+        }
+        value.assume_init()
+    }
+}
+
+/// Restrict the explored paths to those on which `cond` holds.
+#[inline]
+pub fn assume(cond: bool) {
+    // SAFETY: `CVT_assume` only records a path constraint for the Prover.
+    unsafe { intrinsics::CVT_assume(cond) }
+}
+
+/// Assert that `cond` holds on every explored path.
+#[inline]
+pub fn assert(cond: bool) {
+    // SAFETY: `CVT_assert` only records a proof obligation for the Prover.
+    unsafe { intrinsics::CVT_assert(cond) }
+}
+
+/// Ask the Prover to prove that some path *can* reach a state on which `cond`
+/// holds — the reachability dual of [`assert`], which proves `cond` holds on
+/// *every* path.
+///
+/// This is the tool for detecting vacuous rules: placing `satisfy(x == 5)`
+/// after a chain of [`assume`]s confirms those assumptions are jointly
+/// satisfiable (and yields a concrete witness trace). If the `satisfy` fails,
+/// no path that passes the `assume`s reaches a state where `cond` holds; a
+/// failing `satisfy(true)` means the `assume`s contradict each other, so any
+/// following [`assert`] would otherwise pass vacuously.
+#[inline]
+pub fn satisfy(cond: bool) {
+    // SAFETY: `CVT_satisfy` only records a reachability obligation for the
+    // Prover.
+    unsafe { intrinsics::CVT_satisfy(cond) }
+}
+
+/// Constrain a nondeterministic float to be finite, ruling out `NaN` and
+/// `±∞`. Use this before reasoning about symbolic floating-point arithmetic.
+#[inline]
+pub fn assume_finite(x: f64) {
+    assume(x.is_finite());
+}
+
+/// Assert that `x` rounds to the nearest integer `n` under round-half-to-even
+/// (the default IEEE-754 rounding mode, matching `nearbyint`).
+///
+/// Let `d = x - n`. `x` rounds to `n` exactly when `d` lies strictly within
+/// `(-0.5, 0.5)`, or lies on a half-way boundary (`|d| == 0.5`) and `n` is even
+/// — the "ties to even" rule.
+#[inline]
+pub fn assert_rounds_to(x: f64, n: i64) {
+    let d = x - n as f64;
+    let within = d > -0.5 && d < 0.5;
+    let ties_to_even = (d == 0.5 || d == -0.5) && n % 2 == 0;
+    assert(within || ties_to_even);
+}
+
+/// Like [`assume`], but tag the constraint with the caller's source location so
+/// the Prover can attribute it to the exact Rust position.
+#[track_caller]
+#[inline]
+pub fn assume_at(cond: bool) {
+    let loc = core::panic::Location::caller();
+    let file = loc.file();
+    // SAFETY: `file` outlives the call and the pointer/length pair describes a
+    // valid UTF-8 slice; the intrinsic only records a path constraint.
+    unsafe {
+        intrinsics::CVT_assume_with_location(cond, file.as_ptr(), file.len(), loc.line());
+    }
+}
+
+/// Like [`assert`], but tag the proof obligation with the caller's source
+/// location so a violated rule points at the exact Rust position rather than an
+/// opaque mangled symbol.
+#[track_caller]
+#[inline]
+pub fn assert_at(cond: bool) {
+    let loc = core::panic::Location::caller();
+    let file = loc.file();
+    // SAFETY: `file` outlives the call and the pointer/length pair describes a
+    // valid UTF-8 slice; the intrinsic only records a proof obligation.
+    unsafe {
+        intrinsics::CVT_assert_with_location(cond, file.as_ptr(), file.len(), loc.line());
+    }
+}
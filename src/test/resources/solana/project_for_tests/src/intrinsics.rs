@@ -0,0 +1,27 @@
+//! Raw FFI declarations for the Certora Prover's `CVT_*` intrinsics.
+//!
+//! The Prover supplies these symbols at verification time; they have no
+//! meaningful definition when linked outside of it, which is why each one is an
+//! `unsafe extern` function. The common path should go through the safe
+//! wrappers in [`crate::cvt::prelude`] rather than calling these directly.
+
+#[allow(non_snake_case)]
+extern "C" {
+    pub fn CVT_nondet_u8() -> u8;
+    pub fn CVT_nondet_u16() -> u16;
+    pub fn CVT_nondet_u32() -> u32;
+    pub fn CVT_nondet_u64() -> u64;
+    pub fn CVT_nondet_u128() -> u128;
+    pub fn CVT_nondet_i8() -> i8;
+    pub fn CVT_nondet_i16() -> i16;
+    pub fn CVT_nondet_i32() -> i32;
+    pub fn CVT_nondet_i64() -> i64;
+    pub fn CVT_nondet_i128() -> i128;
+    pub fn CVT_nondet_f32() -> f32;
+    pub fn CVT_nondet_f64() -> f64;
+    pub fn CVT_assume(cond: bool);
+    pub fn CVT_assert(cond: bool);
+    pub fn CVT_satisfy(cond: bool);
+    pub fn CVT_assume_with_location(cond: bool, file_ptr: *const u8, file_len: usize, line: u32);
+    pub fn CVT_assert_with_location(cond: bool, file_ptr: *const u8, file_len: usize, line: u32);
+}
@@ -0,0 +1,8 @@
+//! Test fixture crate exercising the Certora Prover's `CVT_*` intrinsics and
+//! the safe wrappers layered on top of them.
+
+pub mod intrinsics;
+
+pub mod cvt;
+
+mod rule_locations;